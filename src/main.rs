@@ -12,34 +12,173 @@
 
 mod parser;
 
-use std::fs::File;
-use std::io::Read;
+use std::fs::OpenOptions;
+use std::io::{Seek, SeekFrom, Write};
 
-fn read_elf_header(path: &str) -> std::io::Result<Vec<u8>> {
-    let mut file = File::open(path)?;
-    let mut header = vec![0; 64];
-    file.read_exact(&mut header)?;
+// Parsed from `--set-os-abi`/`--set-abi-version`/`--set-type`/`--set-machine`; applied to the
+// header in place and written back to the file, akin to `elfedit`.
+struct EditRequest {
+    os_abi: Option<String>,
+    abi_version: Option<u8>,
+    e_type: Option<String>,
+    machine: Option<u16>,
+}
+
+fn parse_edit_args(args: &[String]) -> EditRequest {
+    let mut edit = EditRequest {
+        os_abi: None,
+        abi_version: None,
+        e_type: None,
+        machine: None,
+    };
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--set-os-abi" if i + 1 < args.len() => {
+                edit.os_abi = Some(args[i + 1].clone());
+                i += 2;
+            }
+            "--set-abi-version" if i + 1 < args.len() => {
+                edit.abi_version = args[i + 1].parse().ok();
+                i += 2;
+            }
+            "--set-type" if i + 1 < args.len() => {
+                edit.e_type = Some(args[i + 1].clone());
+                i += 2;
+            }
+            "--set-machine" if i + 1 < args.len() => {
+                edit.machine = args[i + 1].parse().ok();
+                i += 2;
+            }
+            _ => i += 1,
+        }
+    }
+
+    edit
+}
+
+fn apply_edits(elf_path: &str, edit: &EditRequest) -> Result<(), parser::ElfParseError> {
+    let mut elf_header = parser::parse_file(elf_path)?;
+
+    if let Some(name) = &edit.os_abi {
+        match parser::IdentOSABI::parse(name) {
+            Some(os_abi) => elf_header.set_os_abi(os_abi),
+            None => eprintln!("Unknown OS/ABI: {}", name),
+        }
+    }
+
+    if let Some(abi_version) = edit.abi_version {
+        elf_header.set_abi_version(abi_version);
+    }
+
+    if let Some(name) = &edit.e_type {
+        match parser::ElfType::parse(name) {
+            Some(e_type) => elf_header.set_type(e_type),
+            None => eprintln!("Unknown ELF type: {}", name),
+        }
+    }
+
+    if let Some(machine) = edit.machine {
+        elf_header.set_machine(parser::ElfMachine::from_u16(machine)?);
+    }
+
+    let new_header = elf_header.to_bytes();
+    let mut file = OpenOptions::new()
+        .write(true)
+        .open(elf_path)
+        .map_err(parser::ElfParseError::IoError)?;
+    file.seek(SeekFrom::Start(0))
+        .map_err(parser::ElfParseError::IoError)?;
+    file.write_all(&new_header).map_err(parser::ElfParseError::IoError)?;
 
-    Ok(header)
+    Ok(())
+}
+
+// Dumps whichever of `-l`/`-S`/`-s`/`--abi-stub` the caller asked for, readelf-style.
+fn dump_extra(elf_path: &str, elf_header: &parser::ElfHeader, args: &[String]) -> Result<(), parser::ElfParseError> {
+    let want_program_headers = args.iter().any(|a| a == "--program-headers" || a == "-l");
+    let want_section_headers = args.iter().any(|a| a == "--section-headers" || a == "-S");
+    let want_symbols = args.iter().any(|a| a == "--symbols" || a == "-s");
+    let want_abi_stub = args.iter().any(|a| a == "--abi-stub");
+
+    if !(want_program_headers || want_section_headers || want_symbols || want_abi_stub) {
+        return Ok(());
+    }
+
+    let file_bytes = std::fs::read(elf_path).map_err(parser::ElfParseError::IoError)?;
+
+    if want_program_headers {
+        println!("\nProgram Headers:");
+        for ph in elf_header.parse_program_headers(&file_bytes)? {
+            println!("{}", ph);
+        }
+    }
+
+    if want_section_headers {
+        println!("\nSection Headers:");
+        for sh in elf_header.parse_section_headers(&file_bytes)? {
+            println!("{}", sh);
+        }
+    }
+
+    if want_symbols {
+        println!("\nSymbol table:");
+        let sections = elf_header.parse_section_headers(&file_bytes)?;
+        let symtab = sections
+            .iter()
+            .find(|s| s.name == ".symtab")
+            .or_else(|| sections.iter().find(|s| s.name == ".dynsym"));
+        if let Some(symtab) = symtab {
+            let strtab = sections.get(symtab.link as usize);
+            if let Some(strtab) = strtab {
+                for symbol in elf_header.parse_symbols(&file_bytes, symtab, strtab)? {
+                    println!("{}", symbol);
+                }
+            }
+        }
+    }
+
+    if want_abi_stub {
+        println!("\nABI stub:");
+        print!("{}", elf_header.generate_abi_stub(&file_bytes)?);
+    }
+
+    Ok(())
 }
 
 fn main() {
     let args: Vec<String> = std::env::args().collect();
-    if args.len() != 2 {
-        eprintln!("Usage: {} <elf-file>", args[0]);
+    if args.len() < 2 {
+        eprintln!(
+            "Usage: {} <elf-file> [-l|--program-headers] [-S|--section-headers] [-s|--symbols] [--abi-stub] [--set-os-abi NAME] [--set-abi-version N] [--set-type NAME] [--set-machine N]",
+            args[0]
+        );
         std::process::exit(1);
     }
 
     println!("Reading ELF file: {}", args[1]);
 
     let elf_path = &args[1];
-    match read_elf_header(elf_path) {
-        Ok(header) => {
-            let elf_header = parser::ElfHeader::from_bytes(&header);
-            elf_header.print();
+    match parser::parse_file(elf_path) {
+        Ok(elf_header) => {
+            println!("{}", elf_header);
+
+            if let Err(e) = dump_extra(elf_path, &elf_header, &args[2..]) {
+                eprintln!("Error parsing ELF file: {}", e);
+                std::process::exit(1);
+            }
+
+            let edit = parse_edit_args(&args[2..]);
+            if edit.os_abi.is_some() || edit.abi_version.is_some() || edit.e_type.is_some() || edit.machine.is_some() {
+                if let Err(e) = apply_edits(elf_path, &edit) {
+                    eprintln!("Error editing ELF header: {}", e);
+                    std::process::exit(1);
+                }
+            }
         }
         Err(e) => {
-            eprintln!("Error reading ELF file: {}", e);
+            eprintln!("Error parsing ELF file: {}", e);
             std::process::exit(1);
         }
     }
@@ -1,4 +1,10 @@
+// Variant names mirror the ELF spec's own identifiers (NSK, AROS, EtDyn, PtDynamic, ...) so they
+// read the same as readelf/the gABI docs; clippy's idiomatic-Rust renaming would obscure that.
+#![allow(clippy::upper_case_acronyms, clippy::enum_variant_names)]
+
 use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fs::File;
+use std::io::Read;
 
 #[derive(Debug)]
 pub enum ElfParseError {
@@ -7,7 +13,6 @@ pub enum ElfParseError {
     InvalidClass,
     InvalidData,
     InvalidVersion,
-    InvalidOsAbi,
     InvalidType,
     InvalidMachine,
     ReservedOsAbi,
@@ -22,7 +27,6 @@ impl Display for ElfParseError {
             ElfParseError::InvalidClass => write!(f, "Invalid ELF class"),
             ElfParseError::InvalidData => write!(f, "Invalid ELF data encoding"),
             ElfParseError::InvalidVersion => write!(f, "Invalid ELF version"),
-            ElfParseError::InvalidOsAbi => write!(f, "Invalid ELF OS/ABI"),
             ElfParseError::InvalidType => write!(f, "Invalid ELF type"),
             ElfParseError::InvalidMachine => write!(f, "Invalid ELF machine"),
             ElfParseError::ReservedOsAbi => write!(f, "Reserved OS/ABI value"),
@@ -33,12 +37,28 @@ impl Display for ElfParseError {
 
 impl std::error::Error for ElfParseError {}
 
+// Reads the whole file (not just the fixed-size header) and parses its ELF header. Only the
+// header is returned; the program/section/symbol parsers below take the file bytes as an argument.
+pub fn parse_file(path: &str) -> Result<ElfHeader, ElfParseError> {
+    let mut file = File::open(path).map_err(ElfParseError::IoError)?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes).map_err(ElfParseError::IoError)?;
+
+    ElfHeader::from_bytes(&bytes)
+}
+
 #[derive(Debug, Clone, Copy)]
 enum IdentClass {
     ELFCLASS32 = 1,
     ELFCLASS64 = 2,
 }
 
+impl IdentClass {
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+}
+
 impl Display for IdentClass {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         match self {
@@ -54,6 +74,12 @@ enum IdentData {
     ELFDATA2MSB = 2,
 }
 
+impl IdentData {
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+}
+
 impl Display for IdentData {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         match self {
@@ -63,12 +89,80 @@ impl Display for IdentData {
     }
 }
 
+// Reads a u16/u32/u64 out of `bytes` honoring the encoding recorded in `e_ident[EI_DATA]`,
+// so the same decoding path works for both little- and big-endian ELF files.
+fn read_u16(bytes: &[u8], offset: usize, order: IdentData) -> u16 {
+    let chunk = [bytes[offset], bytes[offset + 1]];
+    match order {
+        IdentData::ELFDATA2LSB => u16::from_le_bytes(chunk),
+        IdentData::ELFDATA2MSB => u16::from_be_bytes(chunk),
+    }
+}
+
+fn read_u32(bytes: &[u8], offset: usize, order: IdentData) -> u32 {
+    let chunk = [
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ];
+    match order {
+        IdentData::ELFDATA2LSB => u32::from_le_bytes(chunk),
+        IdentData::ELFDATA2MSB => u32::from_be_bytes(chunk),
+    }
+}
+
+fn read_u64(bytes: &[u8], offset: usize, order: IdentData) -> u64 {
+    let chunk = [
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+        bytes[offset + 4],
+        bytes[offset + 5],
+        bytes[offset + 6],
+        bytes[offset + 7],
+    ];
+    match order {
+        IdentData::ELFDATA2LSB => u64::from_le_bytes(chunk),
+        IdentData::ELFDATA2MSB => u64::from_be_bytes(chunk),
+    }
+}
+
+// Mirror image of read_u16/read_u32/read_u64, used when serializing a header back to bytes.
+fn write_u16(value: u16, order: IdentData) -> [u8; 2] {
+    match order {
+        IdentData::ELFDATA2LSB => value.to_le_bytes(),
+        IdentData::ELFDATA2MSB => value.to_be_bytes(),
+    }
+}
+
+fn write_u32(value: u32, order: IdentData) -> [u8; 4] {
+    match order {
+        IdentData::ELFDATA2LSB => value.to_le_bytes(),
+        IdentData::ELFDATA2MSB => value.to_be_bytes(),
+    }
+}
+
+fn write_u64(value: u64, order: IdentData) -> [u8; 8] {
+    match order {
+        IdentData::ELFDATA2LSB => value.to_le_bytes(),
+        IdentData::ELFDATA2MSB => value.to_be_bytes(),
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum IdentVersion {
     EvNone = 0,
     EvCurrent = 1,
 }
 
+impl IdentVersion {
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+}
+
 impl Display for IdentVersion {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         match self {
@@ -78,8 +172,8 @@ impl Display for IdentVersion {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum IdentOSABI {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentOSABI {
     NONE = 0,
     HPUX = 1,
     NETBSD = 2,
@@ -125,6 +219,37 @@ impl Display for IdentOSABI {
     }
 }
 
+impl IdentOSABI {
+    fn to_u8(self) -> u8 {
+        self as u8
+    }
+
+    // Parses the name of an OS/ABI enum variant, case-insensitively (e.g. "gnu" -> GNU).
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "NONE" => Some(IdentOSABI::NONE),
+            "HPUX" => Some(IdentOSABI::HPUX),
+            "NETBSD" => Some(IdentOSABI::NETBSD),
+            "GNU" | "LINUX" => Some(IdentOSABI::GNU),
+            "SOLARIS" => Some(IdentOSABI::SOLARIS),
+            "AIX" => Some(IdentOSABI::AIX),
+            "IRIX" => Some(IdentOSABI::IRIX),
+            "FREEBSD" => Some(IdentOSABI::FREEBSD),
+            "TRU64" => Some(IdentOSABI::TRU64),
+            "MODESTO" => Some(IdentOSABI::MODESTO),
+            "OPENBSD" => Some(IdentOSABI::OPENBSD),
+            "OPENVMS" => Some(IdentOSABI::OPENVMS),
+            "NSK" => Some(IdentOSABI::NSK),
+            "AROS" => Some(IdentOSABI::AROS),
+            "FENIXOS" => Some(IdentOSABI::FENIXOS),
+            "CLOUDABI" => Some(IdentOSABI::CLOUDABI),
+            "OPENVOS" => Some(IdentOSABI::OPENVOS),
+            "STANDALONE" => Some(IdentOSABI::STANDALONE),
+            _ => None,
+        }
+    }
+}
+
 // ELF Identification structure
 struct EIdent {
     magic: [u8; 4],
@@ -201,6 +326,18 @@ impl EIdent {
             abi_version,
         })
     }
+
+    // Re-serializes e_ident, mapping each enum back to its numeric discriminant.
+    fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[0..4].copy_from_slice(&self.magic);
+        bytes[4] = self.class.to_u8();
+        bytes[5] = self.data.to_u8();
+        bytes[6] = self.version.to_u8();
+        bytes[7] = self.os_abi.to_u8();
+        bytes[8] = self.abi_version;
+        bytes
+    }
 }
 
 impl Display for EIdent {
@@ -215,8 +352,8 @@ impl Display for EIdent {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum ElfType {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElfType {
     EtNone = 0,
     EtRel = 1,
     EtExec = 2,
@@ -240,8 +377,27 @@ impl Display for ElfType {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum ElfMachine {
+impl ElfType {
+    fn to_u16(self) -> u16 {
+        self as u16
+    }
+
+    // Parses an ELF type name without its `Et` prefix, case-insensitively (e.g. "exec" -> EtExec).
+    // Only NONE/REL/EXEC/DYN/CORE are accepted.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "NONE" => Some(ElfType::EtNone),
+            "REL" => Some(ElfType::EtRel),
+            "EXEC" => Some(ElfType::EtExec),
+            "DYN" => Some(ElfType::EtDyn),
+            "CORE" => Some(ElfType::EtCore),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElfMachine {
     EmNone = 0,
     EmM32 = 1,
     EmSparc = 2,
@@ -295,6 +451,43 @@ impl Display for ElfMachine {
     }
 }
 
+impl ElfMachine {
+    fn to_u16(self) -> u16 {
+        self as u16
+    }
+
+    pub fn from_u16(value: u16) -> Result<Self, ElfParseError> {
+        match value {
+            0 => Ok(ElfMachine::EmNone),
+            1 => Ok(ElfMachine::EmM32),
+            2 => Ok(ElfMachine::EmSparc),
+            3 => Ok(ElfMachine::Em386),
+            4 => Ok(ElfMachine::Em68k),
+            5 => Ok(ElfMachine::Em88k),
+            7 => Ok(ElfMachine::Em860),
+            8 => Ok(ElfMachine::EmMips),
+            9 => Ok(ElfMachine::EmS370),
+            10 => Ok(ElfMachine::EmMipsRs3Le),
+            15 => Ok(ElfMachine::EmParisc),
+            17 => Ok(ElfMachine::EmVpp500),
+            18 => Ok(ElfMachine::EmSparc32Plus),
+            19 => Ok(ElfMachine::Em960),
+            20 => Ok(ElfMachine::EmPpc),
+            21 => Ok(ElfMachine::EmPpc64),
+            22 => Ok(ElfMachine::EmS390),
+            62 => Ok(ElfMachine::EmX8664),
+            183 => Ok(ElfMachine::EmAarch64),
+            243 => Ok(ElfMachine::EmRiscv),
+            0xff00 => Ok(ElfMachine::EmLoProc),
+            0xffff => Ok(ElfMachine::EmHiProc),
+            _ => {
+                eprintln!("Unknown machine type: {}", value);
+                Err(ElfParseError::InvalidMachine)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum ElfVersion {
     EvNone = 0,
@@ -310,6 +503,12 @@ impl Display for ElfVersion {
     }
 }
 
+impl ElfVersion {
+    fn to_u32(self) -> u32 {
+        self as u32
+    }
+}
+
 // ELF Header structure
 pub struct ElfHeader {
     ident: EIdent,
@@ -330,13 +529,22 @@ pub struct ElfHeader {
 
 impl ElfHeader {
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, ElfParseError> {
-        if bytes.len() < 64 {
+        if bytes.len() < 16 {
             return Err(ElfParseError::InvalidSize);
         }
 
         let ident = EIdent::from_bytes(&bytes[0..16])?;
+        let order = ident.data;
 
-        let e_type = match u16::from_le_bytes([bytes[16], bytes[17]]) {
+        let min_size = match ident.class {
+            IdentClass::ELFCLASS32 => 52,
+            IdentClass::ELFCLASS64 => 64,
+        };
+        if bytes.len() < min_size {
+            return Err(ElfParseError::InvalidSize);
+        }
+
+        let e_type = match read_u16(bytes, 16, order) {
             0 => ElfType::EtNone,
             1 => ElfType::EtRel,
             2 => ElfType::EtExec,
@@ -347,61 +555,41 @@ impl ElfHeader {
             _ => return Err(ElfParseError::InvalidType),
         };
 
-        let machine_value = u16::from_le_bytes([bytes[18], bytes[19]]);
-        let machine = match machine_value {
-            0 => ElfMachine::EmNone,
-            1 => ElfMachine::EmM32,
-            2 => ElfMachine::EmSparc,
-            3 => ElfMachine::Em386,
-            4 => ElfMachine::Em68k,
-            5 => ElfMachine::Em88k,
-            7 => ElfMachine::Em860,
-            8 => ElfMachine::EmMips,
-            9 => ElfMachine::EmS370,
-            10 => ElfMachine::EmMipsRs3Le,
-            15 => ElfMachine::EmParisc,
-            17 => ElfMachine::EmVpp500,
-            18 => ElfMachine::EmSparc32Plus,
-            19 => ElfMachine::Em960,
-            20 => ElfMachine::EmPpc,
-            21 => ElfMachine::EmPpc64,
-            22 => ElfMachine::EmS390,
-            62 => ElfMachine::EmX8664, // x86-64
-            183 => ElfMachine::EmAarch64,
-            243 => ElfMachine::EmRiscv,
-            0xff00 => ElfMachine::EmLoProc,
-            0xffff => ElfMachine::EmHiProc,
-            _ => {
-                eprintln!("Unknown machine type: {}", machine_value);
-                return Err(ElfParseError::InvalidMachine);
-            }
-        };
+        let machine = ElfMachine::from_u16(read_u16(bytes, 18, order))?;
 
-        let version = match u32::from_le_bytes([bytes[20], bytes[21], bytes[22], bytes[23]]) {
+        let version = match read_u32(bytes, 20, order) {
             0 => ElfVersion::EvNone,
             1 => ElfVersion::EvCurrent,
             _ => return Err(ElfParseError::InvalidVersion),
         };
 
-        let entry = u64::from_le_bytes([
-            bytes[24], bytes[25], bytes[26], bytes[27], bytes[28], bytes[29], bytes[30], bytes[31],
-        ]);
-
-        let phoff = u64::from_le_bytes([
-            bytes[32], bytes[33], bytes[34], bytes[35], bytes[36], bytes[37], bytes[38], bytes[39],
-        ]);
-
-        let shoff = u64::from_le_bytes([
-            bytes[40], bytes[41], bytes[42], bytes[43], bytes[44], bytes[45], bytes[46], bytes[47],
-        ]);
-
-        let flags = u32::from_le_bytes([bytes[48], bytes[49], bytes[50], bytes[51]]);
-        let ehsize = u16::from_le_bytes([bytes[52], bytes[53]]);
-        let phentsize = u16::from_le_bytes([bytes[54], bytes[55]]);
-        let phnum = u16::from_le_bytes([bytes[56], bytes[57]]);
-        let shentsize = u16::from_le_bytes([bytes[58], bytes[59]]);
-        let shnum = u16::from_le_bytes([bytes[60], bytes[61]]);
-        let shstrndx = u16::from_le_bytes([bytes[62], bytes[63]]);
+        let (entry, phoff, shoff, flags, ehsize, phentsize, phnum, shentsize, shnum, shstrndx) =
+            match ident.class {
+                IdentClass::ELFCLASS64 => (
+                    read_u64(bytes, 24, order),
+                    read_u64(bytes, 32, order),
+                    read_u64(bytes, 40, order),
+                    read_u32(bytes, 48, order),
+                    read_u16(bytes, 52, order),
+                    read_u16(bytes, 54, order),
+                    read_u16(bytes, 56, order),
+                    read_u16(bytes, 58, order),
+                    read_u16(bytes, 60, order),
+                    read_u16(bytes, 62, order),
+                ),
+                IdentClass::ELFCLASS32 => (
+                    read_u32(bytes, 24, order) as u64,
+                    read_u32(bytes, 28, order) as u64,
+                    read_u32(bytes, 32, order) as u64,
+                    read_u32(bytes, 36, order),
+                    read_u16(bytes, 40, order),
+                    read_u16(bytes, 42, order),
+                    read_u16(bytes, 44, order),
+                    read_u16(bytes, 46, order),
+                    read_u16(bytes, 48, order),
+                    read_u16(bytes, 50, order),
+                ),
+            };
 
         Ok(ElfHeader {
             ident,
@@ -420,6 +608,472 @@ impl ElfHeader {
             shstrndx,
         })
     }
+
+    pub fn parse_program_headers(&self, file_bytes: &[u8]) -> Result<Vec<ProgramHeader>, ElfParseError> {
+        let order = self.ident.data;
+        let class = self.ident.class;
+        let entsize = self.phentsize as usize;
+        let min_entsize = match class {
+            IdentClass::ELFCLASS64 => 56,
+            IdentClass::ELFCLASS32 => 32,
+        };
+        if entsize < min_entsize {
+            return Err(ElfParseError::InvalidSize);
+        }
+        let mut headers = Vec::with_capacity(self.phnum as usize);
+
+        for i in 0..self.phnum as usize {
+            let start = self.phoff as usize + i * entsize;
+            let end = start
+                .checked_add(entsize)
+                .ok_or(ElfParseError::InvalidSize)?;
+            let entry = file_bytes
+                .get(start..end)
+                .ok_or(ElfParseError::InvalidSize)?;
+
+            let (p_type, p_flags, p_offset, p_vaddr, p_paddr, p_filesz, p_memsz, p_align) =
+                match class {
+                    IdentClass::ELFCLASS64 => (
+                        read_u32(entry, 0, order),
+                        read_u32(entry, 4, order),
+                        read_u64(entry, 8, order),
+                        read_u64(entry, 16, order),
+                        read_u64(entry, 24, order),
+                        read_u64(entry, 32, order),
+                        read_u64(entry, 40, order),
+                        read_u64(entry, 48, order),
+                    ),
+                    IdentClass::ELFCLASS32 => (
+                        read_u32(entry, 0, order),
+                        read_u32(entry, 24, order),
+                        read_u32(entry, 4, order) as u64,
+                        read_u32(entry, 8, order) as u64,
+                        read_u32(entry, 12, order) as u64,
+                        read_u32(entry, 16, order) as u64,
+                        read_u32(entry, 20, order) as u64,
+                        read_u32(entry, 28, order) as u64,
+                    ),
+                };
+
+            headers.push(ProgramHeader {
+                p_type: SegmentType::from(p_type),
+                p_flags,
+                p_offset,
+                p_vaddr,
+                p_paddr,
+                p_filesz,
+                p_memsz,
+                p_align,
+            });
+        }
+
+        Ok(headers)
+    }
+
+    pub fn parse_section_headers(&self, file_bytes: &[u8]) -> Result<Vec<SectionHeader>, ElfParseError> {
+        let order = self.ident.data;
+        let class = self.ident.class;
+        let entsize = self.shentsize as usize;
+        let min_entsize = match class {
+            IdentClass::ELFCLASS64 => 64,
+            IdentClass::ELFCLASS32 => 40,
+        };
+        if entsize < min_entsize {
+            return Err(ElfParseError::InvalidSize);
+        }
+        let mut raw = Vec::with_capacity(self.shnum as usize);
+
+        for i in 0..self.shnum as usize {
+            let start = self.shoff as usize + i * entsize;
+            let end = start
+                .checked_add(entsize)
+                .ok_or(ElfParseError::InvalidSize)?;
+            let entry = file_bytes
+                .get(start..end)
+                .ok_or(ElfParseError::InvalidSize)?;
+
+            let (sh_name, sh_type, sh_flags, sh_addr, sh_offset, sh_size, sh_link, sh_info, sh_addralign, sh_entsize) =
+                match class {
+                    IdentClass::ELFCLASS64 => (
+                        read_u32(entry, 0, order),
+                        read_u32(entry, 4, order),
+                        read_u64(entry, 8, order),
+                        read_u64(entry, 16, order),
+                        read_u64(entry, 24, order),
+                        read_u64(entry, 32, order),
+                        read_u32(entry, 40, order),
+                        read_u32(entry, 44, order),
+                        read_u64(entry, 48, order),
+                        read_u64(entry, 56, order),
+                    ),
+                    IdentClass::ELFCLASS32 => (
+                        read_u32(entry, 0, order),
+                        read_u32(entry, 4, order),
+                        read_u32(entry, 8, order) as u64,
+                        read_u32(entry, 12, order) as u64,
+                        read_u32(entry, 16, order) as u64,
+                        read_u32(entry, 20, order) as u64,
+                        read_u32(entry, 24, order),
+                        read_u32(entry, 28, order),
+                        read_u32(entry, 32, order) as u64,
+                        read_u32(entry, 36, order) as u64,
+                    ),
+                };
+
+            raw.push(RawSectionHeader {
+                name_offset: sh_name,
+                sh_type: ShType::from(sh_type),
+                flags: sh_flags,
+                addr: sh_addr,
+                offset: sh_offset,
+                size: sh_size,
+                link: sh_link,
+                info: sh_info,
+                addralign: sh_addralign,
+                entsize: sh_entsize,
+            });
+        }
+
+        let shstrndx = self.shstrndx as usize;
+        let strtab = raw.get(shstrndx).ok_or(ElfParseError::InvalidSize)?;
+        let strtab_start = strtab.offset as usize;
+        let strtab_end = strtab_start
+            .checked_add(strtab.size as usize)
+            .ok_or(ElfParseError::InvalidSize)?;
+        let strtab_bytes = file_bytes
+            .get(strtab_start..strtab_end)
+            .ok_or(ElfParseError::InvalidSize)?;
+
+        Ok(raw
+            .into_iter()
+            .map(|section| SectionHeader {
+                name: read_str(strtab_bytes, section.name_offset as usize),
+                sh_type: section.sh_type,
+                flags: section.flags,
+                addr: section.addr,
+                offset: section.offset,
+                size: section.size,
+                link: section.link,
+                info: section.info,
+                addralign: section.addralign,
+                entsize: section.entsize,
+            })
+            .collect())
+    }
+
+    pub fn parse_symbols(
+        &self,
+        file_bytes: &[u8],
+        symtab: &SectionHeader,
+        strtab: &SectionHeader,
+    ) -> Result<Vec<Symbol>, ElfParseError> {
+        let order = self.ident.data;
+        let class = self.ident.class;
+        let entsize = symtab.entsize as usize;
+        if entsize == 0 {
+            return Ok(Vec::new());
+        }
+        let min_entsize = match class {
+            IdentClass::ELFCLASS64 => 24,
+            IdentClass::ELFCLASS32 => 16,
+        };
+        if entsize < min_entsize {
+            return Err(ElfParseError::InvalidSize);
+        }
+        let count = symtab.size as usize / entsize;
+
+        let strtab_start = strtab.offset as usize;
+        let strtab_end = strtab_start
+            .checked_add(strtab.size as usize)
+            .ok_or(ElfParseError::InvalidSize)?;
+        let strtab_bytes = file_bytes
+            .get(strtab_start..strtab_end)
+            .ok_or(ElfParseError::InvalidSize)?;
+
+        let mut symbols = Vec::with_capacity(count);
+        for i in 0..count {
+            let start = symtab.offset as usize + i * entsize;
+            let end = start.checked_add(entsize).ok_or(ElfParseError::InvalidSize)?;
+            let entry = file_bytes
+                .get(start..end)
+                .ok_or(ElfParseError::InvalidSize)?;
+
+            let (st_name, st_info, st_shndx, st_value, st_size) = match class {
+                IdentClass::ELFCLASS64 => (
+                    read_u32(entry, 0, order),
+                    entry[4],
+                    read_u16(entry, 6, order),
+                    read_u64(entry, 8, order),
+                    read_u64(entry, 16, order),
+                ),
+                IdentClass::ELFCLASS32 => (
+                    read_u32(entry, 0, order),
+                    entry[12],
+                    read_u16(entry, 14, order),
+                    read_u32(entry, 4, order) as u64,
+                    read_u32(entry, 8, order) as u64,
+                ),
+            };
+
+            symbols.push(Symbol {
+                name: read_str(strtab_bytes, st_name as usize),
+                binding: SymBinding::from(st_info >> 4),
+                sym_type: SymType::from(st_info & 0xf),
+                shndx: st_shndx,
+                value: st_value,
+                size: st_size,
+            });
+        }
+
+        Ok(symbols)
+    }
+
+    pub fn set_type(&mut self, e_type: ElfType) {
+        self.e_type = e_type;
+    }
+
+    pub fn set_machine(&mut self, machine: ElfMachine) {
+        self.machine = machine;
+    }
+
+    pub fn set_os_abi(&mut self, os_abi: IdentOSABI) {
+        self.ident.os_abi = os_abi;
+    }
+
+    pub fn set_abi_version(&mut self, abi_version: u8) {
+        self.ident.abi_version = abi_version;
+    }
+
+    // Re-serializes the header honoring the current class and data encoding, mapping each
+    // enum back to its numeric discriminant. Mirrors `from_bytes` field-for-field.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let order = self.ident.data;
+        let mut bytes = self.ident.to_bytes().to_vec();
+
+        bytes.extend_from_slice(&write_u16(self.e_type.to_u16(), order));
+        bytes.extend_from_slice(&write_u16(self.machine.to_u16(), order));
+        bytes.extend_from_slice(&write_u32(self.version.to_u32(), order));
+
+        match self.ident.class {
+            IdentClass::ELFCLASS64 => {
+                bytes.extend_from_slice(&write_u64(self.entry, order));
+                bytes.extend_from_slice(&write_u64(self.phoff, order));
+                bytes.extend_from_slice(&write_u64(self.shoff, order));
+            }
+            IdentClass::ELFCLASS32 => {
+                bytes.extend_from_slice(&write_u32(self.entry as u32, order));
+                bytes.extend_from_slice(&write_u32(self.phoff as u32, order));
+                bytes.extend_from_slice(&write_u32(self.shoff as u32, order));
+            }
+        }
+
+        bytes.extend_from_slice(&write_u32(self.flags, order));
+        bytes.extend_from_slice(&write_u16(self.ehsize, order));
+        bytes.extend_from_slice(&write_u16(self.phentsize, order));
+        bytes.extend_from_slice(&write_u16(self.phnum, order));
+        bytes.extend_from_slice(&write_u16(self.shentsize, order));
+        bytes.extend_from_slice(&write_u16(self.shnum, order));
+        bytes.extend_from_slice(&write_u16(self.shstrndx, order));
+
+        bytes
+    }
+
+    // Parses `d_tag`/`d_val` pairs out of a `.dynamic` section or `PT_DYNAMIC` segment, honoring
+    // class and endianness, and stopping at the first `DT_NULL` (or the end of `bytes`).
+    fn parse_dynamic_entries(&self, bytes: &[u8]) -> Vec<DynEntry> {
+        let order = self.ident.data;
+        let entsize = match self.ident.class {
+            IdentClass::ELFCLASS64 => 16,
+            IdentClass::ELFCLASS32 => 8,
+        };
+
+        let mut entries = Vec::new();
+        let mut offset = 0;
+        while offset + entsize <= bytes.len() {
+            let (tag, val) = match self.ident.class {
+                IdentClass::ELFCLASS64 => (
+                    read_u64(bytes, offset, order) as i64,
+                    read_u64(bytes, offset + 8, order),
+                ),
+                IdentClass::ELFCLASS32 => (
+                    read_u32(bytes, offset, order) as i64,
+                    read_u32(bytes, offset + 4, order) as u64,
+                ),
+            };
+
+            if tag == DT_NULL {
+                break;
+            }
+            entries.push(DynEntry { tag, val });
+            offset += entsize;
+        }
+
+        entries
+    }
+
+    // Locates `.dynamic` via the program headers (the only structure guaranteed to exist in an
+    // `EtDyn` file, even when the section header table has been stripped) and resolves
+    // `DT_STRTAB`/`DT_SYMTAB`/`DT_STRSZ` through the `PT_LOAD` vaddr->offset mapping. Returns them
+    // as a synthesized pair of `SectionHeader`s so `parse_symbols` can be reused unchanged, or
+    // `None` when a tag is missing or unmapped, or there is no `DT_HASH`/`DT_GNU_HASH` to size it.
+    fn locate_dynsym_via_dynamic(
+        &self,
+        file_bytes: &[u8],
+        program_headers: &[ProgramHeader],
+    ) -> Option<(SectionHeader, SectionHeader, Vec<DynEntry>)> {
+        let dynamic_ph = program_headers
+            .iter()
+            .find(|ph| ph.p_type == SegmentType::PtDynamic)?;
+
+        let dynamic_start = dynamic_ph.p_offset as usize;
+        let dynamic_end = dynamic_start.checked_add(dynamic_ph.p_filesz as usize)?;
+        let dynamic_bytes = file_bytes.get(dynamic_start..dynamic_end)?;
+        let entries = self.parse_dynamic_entries(dynamic_bytes);
+
+        let symtab_vaddr = entries.iter().find(|e| e.tag == DT_SYMTAB)?.val;
+        let strtab_vaddr = entries.iter().find(|e| e.tag == DT_STRTAB)?.val;
+        let strsz = entries.iter().find(|e| e.tag == DT_STRSZ)?.val;
+        let syment = entries
+            .iter()
+            .find(|e| e.tag == DT_SYMENT)
+            .map(|e| e.val)
+            .unwrap_or(match self.ident.class {
+                IdentClass::ELFCLASS64 => 24,
+                IdentClass::ELFCLASS32 => 16,
+            });
+
+        let symtab_offset = vaddr_to_offset(program_headers, symtab_vaddr)?;
+        let strtab_offset = vaddr_to_offset(program_headers, strtab_vaddr)?;
+
+        let symbol_count = if let Some(hash) = entries.iter().find(|e| e.tag == DT_HASH) {
+            let hash_offset = vaddr_to_offset(program_headers, hash.val)?;
+            classic_hash_symbol_count(file_bytes, hash_offset, self.ident.data)?
+        } else if let Some(gnu_hash) = entries.iter().find(|e| e.tag == DT_GNU_HASH) {
+            let gnu_hash_offset = vaddr_to_offset(program_headers, gnu_hash.val)?;
+            gnu_hash_symbol_count(file_bytes, gnu_hash_offset, self.ident.class, self.ident.data)?
+        } else {
+            return None;
+        };
+
+        let symtab = SectionHeader {
+            name: ".dynsym".to_string(),
+            sh_type: ShType::Dynsym,
+            flags: 0,
+            addr: symtab_vaddr,
+            offset: symtab_offset,
+            size: symbol_count as u64 * syment,
+            link: 0,
+            info: 0,
+            addralign: 0,
+            entsize: syment,
+        };
+        let strtab = SectionHeader {
+            name: ".dynstr".to_string(),
+            sh_type: ShType::Strtab,
+            flags: 0,
+            addr: strtab_vaddr,
+            offset: strtab_offset,
+            size: strsz,
+            link: 0,
+            info: 0,
+            addralign: 0,
+            entsize: 0,
+        };
+
+        Some((symtab, strtab, entries))
+    }
+
+    // Walks `.dynsym`/`.dynstr`/`.dynamic` to build a compact ABI stub: arch, bit-width,
+    // endianness, `DT_SONAME`, `DT_NEEDED` libraries, and the exported (defined, non-local)
+    // dynamic symbols. Mirrors what `llvm-ifs`/`elfabi` emit for diffing a library's ABI.
+    //
+    // `PT_DYNAMIC` plus the `DT_STRTAB`/`DT_SYMTAB`/`DT_STRSZ` tags are used first, since those
+    // are present even when the section header table has been stripped; the section table is
+    // only consulted as a fallback when the dynamic tags can't be resolved (e.g. no hash table
+    // to size `.dynsym` from).
+    pub fn generate_abi_stub(&self, file_bytes: &[u8]) -> Result<AbiStub, ElfParseError> {
+        if !matches!(self.e_type, ElfType::EtDyn) {
+            return Err(ElfParseError::InvalidType);
+        }
+
+        let program_headers = self.parse_program_headers(file_bytes)?;
+        let via_dynamic = self.locate_dynsym_via_dynamic(file_bytes, &program_headers);
+
+        let (dynsym, dynstr, entries) = match via_dynamic {
+            Some((dynsym, dynstr, entries)) => (dynsym, dynstr, entries),
+            None => {
+                let sections = self.parse_section_headers(file_bytes)?;
+                let dynamic = sections
+                    .iter()
+                    .find(|s| s.name == ".dynamic")
+                    .ok_or(ElfParseError::InvalidSize)?;
+                let dynsym = sections
+                    .iter()
+                    .find(|s| s.name == ".dynsym")
+                    .ok_or(ElfParseError::InvalidSize)?;
+                let dynstr = sections
+                    .iter()
+                    .find(|s| s.name == ".dynstr")
+                    .ok_or(ElfParseError::InvalidSize)?;
+
+                let dynamic_start = dynamic.offset as usize;
+                let dynamic_end = dynamic_start
+                    .checked_add(dynamic.size as usize)
+                    .ok_or(ElfParseError::InvalidSize)?;
+                let dynamic_bytes = file_bytes
+                    .get(dynamic_start..dynamic_end)
+                    .ok_or(ElfParseError::InvalidSize)?;
+                let entries = self.parse_dynamic_entries(dynamic_bytes);
+
+                (dynsym.clone(), dynstr.clone(), entries)
+            }
+        };
+
+        let dynstr_start = dynstr.offset as usize;
+        let dynstr_end = dynstr_start
+            .checked_add(dynstr.size as usize)
+            .ok_or(ElfParseError::InvalidSize)?;
+        let dynstr_bytes = file_bytes
+            .get(dynstr_start..dynstr_end)
+            .ok_or(ElfParseError::InvalidSize)?;
+
+        let mut soname = None;
+        let mut needed = Vec::new();
+        for entry in &entries {
+            match entry.tag {
+                DT_NEEDED => needed.push(read_str(dynstr_bytes, entry.val as usize)),
+                DT_SONAME => soname = Some(read_str(dynstr_bytes, entry.val as usize)),
+                _ => {}
+            }
+        }
+
+        let exports = self
+            .parse_symbols(file_bytes, &dynsym, &dynstr)?
+            .into_iter()
+            .filter(|sym| sym.shndx != 0 && sym.binding != SymBinding::Local)
+            .map(|sym| ExportedSymbol {
+                name: sym.name,
+                sym_type: sym.sym_type,
+            })
+            .collect();
+
+        Ok(AbiStub {
+            arch: self.machine,
+            class: self.ident.class,
+            data: self.ident.data,
+            soname,
+            needed,
+            exports,
+        })
+    }
+}
+
+// Reads a NUL-terminated string out of a string table blob starting at `offset`.
+fn read_str(strtab: &[u8], offset: usize) -> String {
+    match strtab.get(offset..) {
+        Some(rest) => rest.iter().take_while(|&&b| b != 0).map(|&b| b as char).collect(),
+        None => String::new(),
+    }
 }
 
 impl Display for ElfHeader {
@@ -440,3 +1094,503 @@ impl Display for ElfHeader {
         write!(f, "Section header string table index: {}", self.shstrndx)
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentType {
+    PtNull,
+    PtLoad,
+    PtDynamic,
+    PtInterp,
+    PtNote,
+    PtPhdr,
+    PtTls,
+    PtGnuEhFrame,
+    PtGnuStack,
+    PtGnuRelro,
+    PtOther(u32),
+}
+
+impl From<u32> for SegmentType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => SegmentType::PtNull,
+            1 => SegmentType::PtLoad,
+            2 => SegmentType::PtDynamic,
+            3 => SegmentType::PtInterp,
+            4 => SegmentType::PtNote,
+            6 => SegmentType::PtPhdr,
+            7 => SegmentType::PtTls,
+            0x6474e550 => SegmentType::PtGnuEhFrame,
+            0x6474e551 => SegmentType::PtGnuStack,
+            0x6474e552 => SegmentType::PtGnuRelro,
+            other => SegmentType::PtOther(other),
+        }
+    }
+}
+
+impl Display for SegmentType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            SegmentType::PtNull => write!(f, "NULL"),
+            SegmentType::PtLoad => write!(f, "LOAD"),
+            SegmentType::PtDynamic => write!(f, "DYNAMIC"),
+            SegmentType::PtInterp => write!(f, "INTERP"),
+            SegmentType::PtNote => write!(f, "NOTE"),
+            SegmentType::PtPhdr => write!(f, "PHDR"),
+            SegmentType::PtTls => write!(f, "TLS"),
+            SegmentType::PtGnuEhFrame => write!(f, "GNU_EH_FRAME"),
+            SegmentType::PtGnuStack => write!(f, "GNU_STACK"),
+            SegmentType::PtGnuRelro => write!(f, "GNU_RELRO"),
+            SegmentType::PtOther(value) => write!(f, "Unknown (0x{:x})", value),
+        }
+    }
+}
+
+// A single PT_* entry from the program header table.
+pub struct ProgramHeader {
+    pub p_type: SegmentType,
+    pub p_flags: u32,
+    pub p_offset: u64,
+    pub p_vaddr: u64,
+    pub p_paddr: u64,
+    pub p_filesz: u64,
+    pub p_memsz: u64,
+    pub p_align: u64,
+}
+
+impl ProgramHeader {
+    // Renders the R/W/X bits of `p_flags` the way `readelf -l` does (PF_X=1, PF_W=2, PF_R=4).
+    fn flags_str(&self) -> String {
+        let r = if self.p_flags & 0x4 != 0 { "R" } else { " " };
+        let w = if self.p_flags & 0x2 != 0 { "W" } else { " " };
+        let x = if self.p_flags & 0x1 != 0 { "E" } else { " " };
+        format!("{}{}{}", r, w, x)
+    }
+}
+
+impl Display for ProgramHeader {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        writeln!(f, "  Type: {}", self.p_type)?;
+        writeln!(f, "  Flags: {}", self.flags_str())?;
+        writeln!(f, "  Offset: 0x{:x}", self.p_offset)?;
+        writeln!(f, "  VirtAddr: 0x{:x}", self.p_vaddr)?;
+        writeln!(f, "  PhysAddr: 0x{:x}", self.p_paddr)?;
+        writeln!(f, "  FileSiz: 0x{:x}", self.p_filesz)?;
+        writeln!(f, "  MemSiz: 0x{:x}", self.p_memsz)?;
+        write!(f, "  Align: 0x{:x}", self.p_align)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShType {
+    Null,
+    Progbits,
+    Symtab,
+    Strtab,
+    Rela,
+    Hash,
+    Dynamic,
+    Note,
+    Nobits,
+    Rel,
+    Dynsym,
+    InitArray,
+    FiniArray,
+    GnuHash,
+    Other(u32),
+}
+
+impl From<u32> for ShType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => ShType::Null,
+            1 => ShType::Progbits,
+            2 => ShType::Symtab,
+            3 => ShType::Strtab,
+            4 => ShType::Rela,
+            5 => ShType::Hash,
+            6 => ShType::Dynamic,
+            7 => ShType::Note,
+            8 => ShType::Nobits,
+            9 => ShType::Rel,
+            11 => ShType::Dynsym,
+            14 => ShType::InitArray,
+            15 => ShType::FiniArray,
+            0x6ffffff6 => ShType::GnuHash,
+            other => ShType::Other(other),
+        }
+    }
+}
+
+impl Display for ShType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            ShType::Null => write!(f, "NULL"),
+            ShType::Progbits => write!(f, "PROGBITS"),
+            ShType::Symtab => write!(f, "SYMTAB"),
+            ShType::Strtab => write!(f, "STRTAB"),
+            ShType::Rela => write!(f, "RELA"),
+            ShType::Hash => write!(f, "HASH"),
+            ShType::Dynamic => write!(f, "DYNAMIC"),
+            ShType::Note => write!(f, "NOTE"),
+            ShType::Nobits => write!(f, "NOBITS"),
+            ShType::Rel => write!(f, "REL"),
+            ShType::Dynsym => write!(f, "DYNSYM"),
+            ShType::InitArray => write!(f, "INIT_ARRAY"),
+            ShType::FiniArray => write!(f, "FINI_ARRAY"),
+            ShType::GnuHash => write!(f, "GNU_HASH"),
+            ShType::Other(value) => write!(f, "Unknown (0x{:x})", value),
+        }
+    }
+}
+
+// Intermediate form of a section header before its name is resolved against shstrtab.
+struct RawSectionHeader {
+    name_offset: u32,
+    sh_type: ShType,
+    flags: u64,
+    addr: u64,
+    offset: u64,
+    size: u64,
+    link: u32,
+    info: u32,
+    addralign: u64,
+    entsize: u64,
+}
+
+// A section header with its name already resolved via `shstrndx`.
+#[derive(Clone)]
+pub struct SectionHeader {
+    pub name: String,
+    pub sh_type: ShType,
+    pub flags: u64,
+    pub addr: u64,
+    pub offset: u64,
+    pub size: u64,
+    pub link: u32,
+    pub info: u32,
+    pub addralign: u64,
+    pub entsize: u64,
+}
+
+impl SectionHeader {
+    // Renders the W/A/X bits of `sh_flags` (SHF_WRITE=0x1, SHF_ALLOC=0x2, SHF_EXECINSTR=0x4).
+    fn flags_str(&self) -> String {
+        let w = if self.flags & 0x1 != 0 { "W" } else { " " };
+        let a = if self.flags & 0x2 != 0 { "A" } else { " " };
+        let x = if self.flags & 0x4 != 0 { "X" } else { " " };
+        format!("{}{}{}", w, a, x)
+    }
+}
+
+impl Display for SectionHeader {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        writeln!(f, "  Name: {}", self.name)?;
+        writeln!(f, "  Type: {}", self.sh_type)?;
+        writeln!(f, "  Flags: {}", self.flags_str())?;
+        writeln!(f, "  Address: 0x{:x}", self.addr)?;
+        writeln!(f, "  Offset: 0x{:x}", self.offset)?;
+        writeln!(f, "  Size: 0x{:x}", self.size)?;
+        writeln!(f, "  Link: {}", self.link)?;
+        writeln!(f, "  Info: {}", self.info)?;
+        writeln!(f, "  Address align: {}", self.addralign)?;
+        write!(f, "  Entry size: {}", self.entsize)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymBinding {
+    Local,
+    Global,
+    Weak,
+    Other(u8),
+}
+
+impl From<u8> for SymBinding {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => SymBinding::Local,
+            1 => SymBinding::Global,
+            2 => SymBinding::Weak,
+            other => SymBinding::Other(other),
+        }
+    }
+}
+
+impl Display for SymBinding {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            SymBinding::Local => write!(f, "LOCAL"),
+            SymBinding::Global => write!(f, "GLOBAL"),
+            SymBinding::Weak => write!(f, "WEAK"),
+            SymBinding::Other(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymType {
+    NoType,
+    Object,
+    Func,
+    Section,
+    File,
+    Other(u8),
+}
+
+impl From<u8> for SymType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => SymType::NoType,
+            1 => SymType::Object,
+            2 => SymType::Func,
+            3 => SymType::Section,
+            4 => SymType::File,
+            other => SymType::Other(other),
+        }
+    }
+}
+
+impl Display for SymType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            SymType::NoType => write!(f, "NOTYPE"),
+            SymType::Object => write!(f, "OBJECT"),
+            SymType::Func => write!(f, "FUNC"),
+            SymType::Section => write!(f, "SECTION"),
+            SymType::File => write!(f, "FILE"),
+            SymType::Other(value) => write!(f, "Unknown ({})", value),
+        }
+    }
+}
+
+// One entry from .symtab/.dynsym, with its name resolved against the linked string table.
+pub struct Symbol {
+    pub name: String,
+    pub binding: SymBinding,
+    pub sym_type: SymType,
+    pub shndx: u16,
+    pub value: u64,
+    pub size: u64,
+}
+
+impl Display for Symbol {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(
+            f,
+            "{:016x} {:>5} {:<7} {:<7} {:>6} {}",
+            self.value, self.size, self.sym_type, self.binding, self.shndx, self.name
+        )
+    }
+}
+
+const DT_NULL: i64 = 0;
+const DT_NEEDED: i64 = 1;
+const DT_HASH: i64 = 4;
+const DT_STRTAB: i64 = 5;
+const DT_SYMTAB: i64 = 6;
+const DT_STRSZ: i64 = 10;
+const DT_SYMENT: i64 = 11;
+const DT_SONAME: i64 = 14;
+const DT_GNU_HASH: i64 = 0x6ffffef5;
+
+// A single `d_tag`/`d_val` pair from `PT_DYNAMIC`/`.dynamic`.
+struct DynEntry {
+    tag: i64,
+    val: u64,
+}
+
+// Translates a runtime virtual address to a file offset via the `PT_LOAD` mapping it falls in
+// (`p_vaddr <= vaddr < p_vaddr + p_filesz`). `DT_STRTAB`/`DT_SYMTAB`/etc. are vaddrs, not file
+// offsets, so this is needed to read them directly out of an unloaded file.
+fn vaddr_to_offset(program_headers: &[ProgramHeader], vaddr: u64) -> Option<u64> {
+    program_headers
+        .iter()
+        .filter(|ph| ph.p_type == SegmentType::PtLoad)
+        .find(|ph| vaddr >= ph.p_vaddr && vaddr < ph.p_vaddr + ph.p_filesz)
+        .map(|ph| ph.p_offset + (vaddr - ph.p_vaddr))
+}
+
+// Recovers the number of symbols in `.dynsym` from a classic SysV `.hash`/`DT_HASH` table, whose
+// second `u32` (`nchain`) equals the symbol table size directly.
+fn classic_hash_symbol_count(file_bytes: &[u8], offset: u64, order: IdentData) -> Option<usize> {
+    let offset = offset as usize;
+    let nchain_offset = offset.checked_add(4)?;
+    if nchain_offset + 4 > file_bytes.len() {
+        return None;
+    }
+    Some(read_u32(file_bytes, nchain_offset, order) as usize)
+}
+
+// Recovers the number of symbols in `.dynsym` from a `.gnu.hash`/`DT_GNU_HASH` table, which (by
+// design) doesn't store a symbol count directly. Mirrors the algorithm LLVM's `ELF.cpp` uses for
+// the same problem: find the highest symbol index named by any hash bucket, then walk that
+// bucket's chain to the last entry (marked by its low bit) to get the final count.
+fn gnu_hash_symbol_count(
+    file_bytes: &[u8],
+    offset: u64,
+    class: IdentClass,
+    order: IdentData,
+) -> Option<usize> {
+    let offset = offset as usize;
+    let header = file_bytes.get(offset..offset.checked_add(16)?)?;
+    let nbuckets = read_u32(header, 0, order) as usize;
+    let symoffset = read_u32(header, 4, order) as usize;
+    let bloom_size = read_u32(header, 8, order) as usize;
+    let bloom_word_size = match class {
+        IdentClass::ELFCLASS64 => 8,
+        IdentClass::ELFCLASS32 => 4,
+    };
+
+    let buckets_start = offset
+        .checked_add(16)?
+        .checked_add(bloom_size.checked_mul(bloom_word_size)?)?;
+    let buckets_end = buckets_start.checked_add(nbuckets.checked_mul(4)?)?;
+    let buckets = file_bytes.get(buckets_start..buckets_end)?;
+
+    let last_sym = (0..nbuckets)
+        .map(|i| read_u32(buckets, i * 4, order) as usize)
+        .max()
+        .unwrap_or(0);
+    if last_sym < symoffset {
+        return Some(symoffset);
+    }
+
+    let mut idx = last_sym - symoffset;
+    loop {
+        let chain_entry_offset = buckets_end.checked_add(idx.checked_mul(4)?)?;
+        let chain_entry = read_u32(
+            file_bytes.get(chain_entry_offset..chain_entry_offset + 4)?,
+            0,
+            order,
+        );
+        if chain_entry & 1 != 0 {
+            return Some(symoffset + idx + 1);
+        }
+        idx += 1;
+    }
+}
+
+// An exported (defined, non-local) dynamic symbol, as captured in an `AbiStub`.
+pub struct ExportedSymbol {
+    pub name: String,
+    pub sym_type: SymType,
+}
+
+// A compact textual ABI stub for an `EtDyn` shared object, akin to what `llvm-ifs`/`elfabi`
+// emit: enough to diff the exported interface of two builds of the same library.
+pub struct AbiStub {
+    pub arch: ElfMachine,
+    class: IdentClass,
+    data: IdentData,
+    pub soname: Option<String>,
+    pub needed: Vec<String>,
+    pub exports: Vec<ExportedSymbol>,
+}
+
+impl Display for AbiStub {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        writeln!(f, "arch: {}", self.arch)?;
+        writeln!(f, "class: {}", self.class)?;
+        writeln!(f, "data: {}", self.data)?;
+        writeln!(f, "soname: {}", self.soname.as_deref().unwrap_or("~"))?;
+        writeln!(f, "needed:")?;
+        for lib in &self.needed {
+            writeln!(f, "  - {}", lib)?;
+        }
+        writeln!(f, "exports:")?;
+        for symbol in &self.exports {
+            writeln!(f, "  - name: {}", symbol.name)?;
+            writeln!(f, "    type: {}", symbol.sym_type)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A minimal but well-formed ELF64, little-endian header: EtDyn/EmX8664, non-zero
+    // offsets/counts so those fields round-trip through something other than zero.
+    fn elf64_header_bytes() -> Vec<u8> {
+        let mut bytes = vec![0u8; 64];
+        bytes[0..4].copy_from_slice(&[0x7f, b'E', b'L', b'F']);
+        bytes[4] = 2; // ELFCLASS64
+        bytes[5] = 1; // ELFDATA2LSB
+        bytes[6] = 1; // EV_CURRENT
+        bytes[7] = 0; // ELFOSABI_NONE
+        bytes[8] = 0; // abi_version
+        bytes[16..18].copy_from_slice(&3u16.to_le_bytes()); // e_type: ET_DYN
+        bytes[18..20].copy_from_slice(&62u16.to_le_bytes()); // e_machine: EM_X86_64
+        bytes[20..24].copy_from_slice(&1u32.to_le_bytes()); // e_version: EV_CURRENT
+        bytes[24..32].copy_from_slice(&0x1000u64.to_le_bytes()); // e_entry
+        bytes[32..40].copy_from_slice(&64u64.to_le_bytes()); // e_phoff
+        bytes[40..48].copy_from_slice(&0x2000u64.to_le_bytes()); // e_shoff
+        bytes[48..52].copy_from_slice(&0u32.to_le_bytes()); // e_flags
+        bytes[52..54].copy_from_slice(&64u16.to_le_bytes()); // e_ehsize
+        bytes[54..56].copy_from_slice(&56u16.to_le_bytes()); // e_phentsize
+        bytes[56..58].copy_from_slice(&1u16.to_le_bytes()); // e_phnum
+        bytes[58..60].copy_from_slice(&64u16.to_le_bytes()); // e_shentsize
+        bytes[60..62].copy_from_slice(&3u16.to_le_bytes()); // e_shnum
+        bytes[62..64].copy_from_slice(&2u16.to_le_bytes()); // e_shstrndx
+        bytes
+    }
+
+    #[test]
+    fn elf64_header_round_trips_through_from_bytes_to_bytes() {
+        let original = elf64_header_bytes();
+        let header = ElfHeader::from_bytes(&original).expect("well-formed header should parse");
+        assert_eq!(header.to_bytes(), original);
+    }
+
+    // An ELF32, little-endian header (EtExec/Em386): e_entry/e_phoff/e_shoff are 4 bytes instead
+    // of 8, shifting every later field 12 bytes earlier, so this exercises the 32-bit offsets.
+    fn elf32_header_bytes() -> Vec<u8> {
+        let mut bytes = vec![0u8; 52];
+        bytes[0..4].copy_from_slice(&[0x7f, b'E', b'L', b'F']);
+        bytes[4] = 1; // ELFCLASS32
+        bytes[5] = 1; // ELFDATA2LSB
+        bytes[6] = 1; // EV_CURRENT
+        bytes[7] = 0; // ELFOSABI_NONE
+        bytes[8] = 0; // abi_version
+        bytes[16..18].copy_from_slice(&2u16.to_le_bytes()); // e_type: ET_EXEC
+        bytes[18..20].copy_from_slice(&3u16.to_le_bytes()); // e_machine: EM_386
+        bytes[20..24].copy_from_slice(&1u32.to_le_bytes()); // e_version: EV_CURRENT
+        bytes[24..28].copy_from_slice(&0x8048000u32.to_le_bytes()); // e_entry
+        bytes[28..32].copy_from_slice(&52u32.to_le_bytes()); // e_phoff
+        bytes[32..36].copy_from_slice(&0x1000u32.to_le_bytes()); // e_shoff
+        bytes[36..40].copy_from_slice(&0u32.to_le_bytes()); // e_flags
+        bytes[40..42].copy_from_slice(&52u16.to_le_bytes()); // e_ehsize
+        bytes[42..44].copy_from_slice(&32u16.to_le_bytes()); // e_phentsize
+        bytes[44..46].copy_from_slice(&1u16.to_le_bytes()); // e_phnum
+        bytes[46..48].copy_from_slice(&40u16.to_le_bytes()); // e_shentsize
+        bytes[48..50].copy_from_slice(&4u16.to_le_bytes()); // e_shnum
+        bytes[50..52].copy_from_slice(&1u16.to_le_bytes()); // e_shstrndx
+        bytes
+    }
+
+    #[test]
+    fn elf32_header_fields_are_read_from_the_32_bit_offsets() {
+        let header = ElfHeader::from_bytes(&elf32_header_bytes()).expect("well-formed header should parse");
+
+        assert_eq!(header.entry, 0x8048000);
+        assert_eq!(header.phoff, 52);
+        assert_eq!(header.shoff, 0x1000);
+        assert_eq!(header.ehsize, 52);
+        assert_eq!(header.phentsize, 32);
+        assert_eq!(header.phnum, 1);
+        assert_eq!(header.shentsize, 40);
+        assert_eq!(header.shnum, 4);
+        assert_eq!(header.shstrndx, 1);
+        assert!(matches!(header.machine, ElfMachine::Em386));
+        assert!(matches!(header.e_type, ElfType::EtExec));
+    }
+
+    #[test]
+    fn elf32_header_round_trips_through_from_bytes_to_bytes() {
+        let original = elf32_header_bytes();
+        let header = ElfHeader::from_bytes(&original).expect("well-formed header should parse");
+        assert_eq!(header.to_bytes(), original);
+    }
+}